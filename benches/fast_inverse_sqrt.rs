@@ -0,0 +1,52 @@
+//! Benchmarks backing the "fast" in `fast_inverse_sqrt`: compares the bit-hack
+//! approximation against `1.0 / x.sqrt()` for scalar inputs at a few magnitudes, and
+//! the batch path against a naive per-element loop at a few array sizes.
+//!
+//! Run with `cargo bench`.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use quake_inverse_sqrt::fast_inverse_sqrt_f32;
+
+fn naive_inverse_sqrt_slice(input: &[f32], out: &mut [f32]) {
+    for (src, dst) in input.iter().zip(out.iter_mut()) {
+        *dst = 1.0 / src.sqrt();
+    }
+}
+
+fn scalar_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scalar");
+
+    for &x in &[1.0f32, 100.0, 1.0e6, 1.0e12] {
+        group.bench_with_input(BenchmarkId::new("fast_inverse_sqrt", x), &x, |b, &x| {
+            b.iter(|| fast_inverse_sqrt_f32(black_box(x)))
+        });
+        group.bench_with_input(BenchmarkId::new("std_inverse_sqrt", x), &x, |b, &x| {
+            b.iter(|| 1.0 / black_box(x).sqrt())
+        });
+    }
+
+    group.finish();
+}
+
+fn batch_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch");
+
+    for &len in &[16usize, 1024, 1 << 16] {
+        let input: Vec<f32> = (1..=len).map(|i| i as f32).collect();
+        let mut out = vec![0.0f32; len];
+
+        group.bench_with_input(BenchmarkId::new("fast_inverse_sqrt_slice", len), &len, |b, _| {
+            b.iter(|| quake_inverse_sqrt::fast_inverse_sqrt_slice(black_box(&input), &mut out))
+        });
+        group.bench_with_input(BenchmarkId::new("naive_inverse_sqrt_slice", len), &len, |b, _| {
+            b.iter(|| naive_inverse_sqrt_slice(black_box(&input), &mut out))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, scalar_benchmarks, batch_benchmarks);
+criterion_main!(benches);
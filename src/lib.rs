@@ -5,15 +5,111 @@
 //!
 //! The result is approximated in favour of speed of execution.
 //!
+//! This crate is `no_std` when built without its default `std` feature: the algorithm is
+//! pure bit manipulation and arithmetic, so the
+//! `fast_inverse_sqrt_f32`/`fast_inverse_sqrt_f64` free functions are `const fn` and can be
+//! used to initialize `const`/`static` lookup tables without a runtime call.
+//!
+//! [`QSqrt::regular_inverse_sqrt`] and [`QSqrt::fast_inverse_sqrt_with_error`] are gated
+//! behind the (default-enabled) `std` feature, since they need the standard library's
+//! `sqrt` to compute an accurate reference value. Disable default features for a pure
+//! `no_std` build without them.
+//!
 //! # Example
 //!
 //! ```
-//! let num: f32 = 4.0.fast_inverse_sqrt();
+//! use quake_inverse_sqrt::QSqrt;
+//!
+//! let num: f32 = 4.0f32.fast_inverse_sqrt_unchecked();
 //! assert!(num > 0.49 && num < 0.51);
 //! ```
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
 const THREE_HALFS: f32 = 1.5;
 const WTF: u32 = 0x5f3759df;
 
+const THREE_HALFS_64: f64 = 1.5;
+const WTF_64: u64 = 0x5fe6eb50c7b537a9;
+
+/// Computes the raw magic-constant estimate plus `N` Newton-Raphson refinement
+/// steps for `f32`, reachable from `const` context (e.g. to build `const`/`static`
+/// lookup tables without a runtime call).
+pub const fn fast_inverse_sqrt_f32_iters<const N: usize>(x: f32) -> f32 {
+    let x2 = x * 0.5;
+
+    // Evil bit hack
+    let i = x.to_bits();
+
+    // What the f*ck
+    let i = WTF - (i >> 1);
+
+    let mut y = f32::from_bits(i);
+
+    // Newton iteration(s)
+    let mut n = 0;
+    while n < N {
+        y = y * (THREE_HALFS - (x2 * y * y));
+        n += 1;
+    }
+
+    y
+}
+
+/// Computes the fast inverse square root of an `f32` using a single Newton
+/// iteration, reachable from `const` context
+pub const fn fast_inverse_sqrt_f32(x: f32) -> f32 {
+    fast_inverse_sqrt_f32_iters::<1>(x)
+}
+
+/// Computes the raw magic-constant estimate plus `N` Newton-Raphson refinement
+/// steps for `f64`, reachable from `const` context
+pub const fn fast_inverse_sqrt_f64_iters<const N: usize>(x: f64) -> f64 {
+    let x2 = x * 0.5;
+
+    // Evil bit hack
+    let i = x.to_bits();
+
+    // What the f*ck
+    let i = WTF_64 - (i >> 1);
+
+    let mut y = f64::from_bits(i);
+
+    // Newton iteration(s)
+    let mut n = 0;
+    while n < N {
+        y = y * (THREE_HALFS_64 - (x2 * y * y));
+        n += 1;
+    }
+
+    y
+}
+
+/// Computes the fast inverse square root of an `f64` using a single Newton
+/// iteration, reachable from `const` context
+pub const fn fast_inverse_sqrt_f64(x: f64) -> f64 {
+    fast_inverse_sqrt_f64_iters::<1>(x)
+}
+
+/// Computes the fast inverse square root of every element of `input`, writing the
+/// results into `out`, without paying per-element trait-dispatch overhead.
+///
+/// This is the normalization use case the algorithm was originally written for:
+/// bulk-normalizing large vertex, particle, or lighting vectors. The inner loop has
+/// no early returns and operates on plain `f32` arithmetic so it auto-vectorizes.
+///
+/// # Panics
+///
+/// Panics if `input` and `out` don't have the same length.
+pub fn fast_inverse_sqrt_slice(input: &[f32], out: &mut [f32]) {
+    assert_eq!(input.len(), out.len());
+
+    for (src, dst) in input.iter().zip(out.iter_mut()) {
+        *dst = fast_inverse_sqrt_f32(*src);
+    }
+}
+
+/// Error type returned by [`QSqrt`]. No built-in impl produces one anymore, but it's
+/// kept as part of the public trait contract for third-party implementations that may.
 #[derive(Debug)]
 pub enum QSqrtError {
     Overflow,
@@ -24,47 +120,78 @@ pub enum QSqrtError {
 pub trait QSqrt {
     type Output;
 
-    /// Computes the fast inverse square root of `self`
-    fn fast_inverse_sqrt(&self) -> Result<Self::Output, QSqrtError>;
+    /// Computes the fast inverse square root of `self`, applying `N` Newton-Raphson
+    /// refinement steps after the magic-constant bit hack.
+    ///
+    /// `N = 0` returns the raw magic-constant estimate (at most ~3.5% relative error),
+    /// `N = 1` matches the original Quake III behaviour (at most ~0.18% relative error),
+    /// and `N = 2` is accurate to a fraction of a percent at the cost of an extra iteration.
+    fn fast_inverse_sqrt_iters<const N: usize>(&self) -> Result<Self::Output, QSqrtError>;
+
+    /// Computes the fast inverse square root of `self` using a single Newton iteration
+    fn fast_inverse_sqrt(&self) -> Result<Self::Output, QSqrtError> {
+        self.fast_inverse_sqrt_iters::<1>()
+    }
 
     /// Like `fast_inverse_sqrt` but panics on errors
     fn fast_inverse_sqrt_unchecked(&self) -> Self::Output {
         self.fast_inverse_sqrt().unwrap()
     }
+
+    /// Computes the accurate inverse square root of `self` via `1.0 / self.sqrt()`,
+    /// for comparison against the fast approximation. Pulls in the standard library's
+    /// `sqrt`, so it is only available with the (default-enabled) `std` feature.
+    #[cfg(feature = "std")]
+    fn regular_inverse_sqrt(&self) -> Result<Self::Output, QSqrtError>;
+
+    /// Computes the fast approximation alongside its relative error versus
+    /// `regular_inverse_sqrt`, i.e. `(fast - exact).abs() / exact`. Requires the
+    /// `std` feature, same as `regular_inverse_sqrt`.
+    #[cfg(feature = "std")]
+    fn fast_inverse_sqrt_with_error(&self) -> Result<(Self::Output, f32), QSqrtError>;
 }
 
 impl QSqrt for f32 {
     type Output = f32;
 
-    fn fast_inverse_sqrt(&self) -> Result<Self::Output, QSqrtError> {
-        let mut y = *self;
-        let mut i: u32;
-        let x2: f32 = self * 0.5;
-
-        // Evil bit hack
-        i = y.to_bits();
-
-        // What the f*ck
-        i = WTF - (i >> 1);
+    fn fast_inverse_sqrt_iters<const N: usize>(&self) -> Result<Self::Output, QSqrtError> {
+        Ok(fast_inverse_sqrt_f32_iters::<N>(*self))
+    }
 
-        y = f32::from_bits(i);
+    #[cfg(feature = "std")]
+    fn regular_inverse_sqrt(&self) -> Result<Self::Output, QSqrtError> {
+        Ok(1.0 / self.sqrt())
+    }
 
-        // Newton iteration
-        y = y * (THREE_HALFS - (x2 * y * y));
+    #[cfg(feature = "std")]
+    fn fast_inverse_sqrt_with_error(&self) -> Result<(Self::Output, f32), QSqrtError> {
+        let fast = self.fast_inverse_sqrt()?;
+        let exact = self.regular_inverse_sqrt()?;
+        let error = (fast - exact).abs() / exact;
 
-        Ok(y)
+        Ok((fast, error))
     }
 }
 
 impl QSqrt for f64 {
-    type Output = f32;
+    type Output = f64;
 
-    fn fast_inverse_sqrt(&self) -> Result<Self::Output, QSqrtError> {
-        if *self >= f32::MIN.into() && *self <= f32::MAX.into() {
-            (*self as f32).fast_inverse_sqrt()
-        } else {
-            Err(QSqrtError::Overflow)
-        }
+    fn fast_inverse_sqrt_iters<const N: usize>(&self) -> Result<Self::Output, QSqrtError> {
+        Ok(fast_inverse_sqrt_f64_iters::<N>(*self))
+    }
+
+    #[cfg(feature = "std")]
+    fn regular_inverse_sqrt(&self) -> Result<Self::Output, QSqrtError> {
+        Ok(1.0 / self.sqrt())
+    }
+
+    #[cfg(feature = "std")]
+    fn fast_inverse_sqrt_with_error(&self) -> Result<(Self::Output, f32), QSqrtError> {
+        let fast = self.fast_inverse_sqrt()?;
+        let exact = self.regular_inverse_sqrt()?;
+        let error = ((fast - exact).abs() / exact) as f32;
+
+        Ok((fast, error))
     }
 }
 
@@ -74,9 +201,21 @@ macro_rules! impl_types {
             impl QSqrt for $ty {
                 type Output = f32;
 
-                fn fast_inverse_sqrt(&self) -> Result<Self::Output, QSqrtError> {
+                fn fast_inverse_sqrt_iters<const N: usize>(&self) -> Result<Self::Output, QSqrtError> {
+                    let value = *self as f32;
+                    value.fast_inverse_sqrt_iters::<N>()
+                }
+
+                #[cfg(feature = "std")]
+                fn regular_inverse_sqrt(&self) -> Result<Self::Output, QSqrtError> {
+                    let value = *self as f32;
+                    value.regular_inverse_sqrt()
+                }
+
+                #[cfg(feature = "std")]
+                fn fast_inverse_sqrt_with_error(&self) -> Result<(Self::Output, f32), QSqrtError> {
                     let value = *self as f32;
-                    value.fast_inverse_sqrt()
+                    value.fast_inverse_sqrt_with_error()
                 }
             }
         )*
@@ -85,9 +224,80 @@ macro_rules! impl_types {
 
 impl_types!(u64, u32, u16, u8, i64, i32, i16, i8, usize, isize);
 
+/// A trait to compute the exact, floored integer square root of a primitive
+/// integer type. Unlike [`QSqrt`], this never approximates: it is the exact
+/// complement for callers that need correctness rather than raw speed.
+pub trait IntegerSquareRoot {
+    /// Computes the floor of the exact square root of `self`, or `None` if `self`
+    /// is negative
+    fn integer_sqrt_checked(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Like `integer_sqrt_checked` but panics on negative input
+    fn integer_sqrt(&self) -> Self
+    where
+        Self: Sized,
+    {
+        self.integer_sqrt_checked().unwrap()
+    }
+}
+
+macro_rules! impl_integer_sqrt_unsigned {
+    ( $($ty: ty),* ) => {
+        $(
+            impl IntegerSquareRoot for $ty {
+                fn integer_sqrt_checked(&self) -> Option<Self> {
+                    let mut n = *self;
+
+                    if n == 0 {
+                        return Some(0);
+                    }
+
+                    let mut c: $ty = 0;
+                    let mut d: $ty = 1 << (((<$ty>::BITS - 1 - n.leading_zeros()) / 2) * 2);
+
+                    while d != 0 {
+                        if n >= c + d {
+                            n -= c + d;
+                            c = (c >> 1) + d;
+                        } else {
+                            c >>= 1;
+                        }
+                        d >>= 2;
+                    }
+
+                    Some(c)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_integer_sqrt_signed {
+    ( $(($ty: ty, $unsigned_ty: ty)),* ) => {
+        $(
+            impl IntegerSquareRoot for $ty {
+                fn integer_sqrt_checked(&self) -> Option<Self> {
+                    if *self < 0 {
+                        None
+                    } else {
+                        (*self as $unsigned_ty)
+                            .integer_sqrt_checked()
+                            .map(|root| root as $ty)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_sqrt_unsigned!(u64, u32, u16, u8, usize);
+impl_integer_sqrt_signed!((i64, u64), (i32, u32), (i16, u16), (i8, u8), (isize, usize));
+
 #[cfg(test)]
 mod tests {
-    use crate::QSqrt;
+    use crate::{IntegerSquareRoot, QSqrt};
 
     macro_rules! make_test {
         ($name: tt, $ty: ty, $value: expr, $expected_lower_bound: expr, $expected_upper_bound: expr) => {
@@ -110,6 +320,103 @@ mod tests {
     make_test!(i32_input, i32, 4, 0.49, 0.51);
     make_test!(i16_input, i16, 4, 0.49, 0.51);
     make_test!(i8_input, i8, 4, 0.49, 0.51);
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn regular_inverse_sqrt_is_exact() {
+        let exact = 4f32.regular_inverse_sqrt().unwrap();
+        assert_eq!(exact, 0.5);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn fast_inverse_sqrt_with_error_reports_small_relative_error() {
+        let (fast, error) = 4f32.fast_inverse_sqrt_with_error().unwrap();
+        let exact = 4f32.regular_inverse_sqrt().unwrap();
+
+        assert_eq!(fast, 4f32.fast_inverse_sqrt_unchecked());
+        assert!(error < 0.01);
+        assert_eq!(error, (fast - exact).abs() / exact);
+    }
+
+    #[test]
+    fn slice_matches_scalar_path() {
+        let input = [1.0f32, 4.0, 9.0, 16.0];
+        let mut out = [0.0f32; 4];
+
+        crate::fast_inverse_sqrt_slice(&input, &mut out);
+
+        for (x, y) in input.iter().zip(out.iter()) {
+            assert_eq!(*y, x.fast_inverse_sqrt_unchecked());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_panics_on_mismatched_lengths() {
+        let input = [1.0f32, 4.0];
+        let mut out = [0.0f32; 1];
+
+        crate::fast_inverse_sqrt_slice(&input, &mut out);
+    }
+
+    #[test]
+    fn const_fn_matches_trait_method() {
+        const FOUR_INV_SQRT: f32 = crate::fast_inverse_sqrt_f32(4.0);
+        assert_eq!(FOUR_INV_SQRT, 4f32.fast_inverse_sqrt_unchecked());
+    }
+
+    #[test]
+    fn iters_zero_is_raw_estimate() {
+        let raw = 4f32.fast_inverse_sqrt_iters::<0>().unwrap();
+        let one_step = 4f32.fast_inverse_sqrt_iters::<1>().unwrap();
+        assert_eq!(raw, f32::from_bits(crate::WTF - (4f32.to_bits() >> 1)));
+        assert_ne!(raw, one_step);
+    }
+
+    #[test]
+    fn iters_two_is_more_accurate_than_one() {
+        let exact = 1.0 / 4f32.sqrt();
+        let one_step = 4f32.fast_inverse_sqrt_iters::<1>().unwrap();
+        let two_steps = 4f32.fast_inverse_sqrt_iters::<2>().unwrap();
+        assert!((two_steps - exact).abs() <= (one_step - exact).abs());
+    }
+
+    macro_rules! make_integer_sqrt_test {
+        ($name: tt, $ty: ty, $value: expr, $expected: expr) => {
+            #[test]
+            fn $name() {
+                let x: $ty = $value;
+                assert_eq!(x.integer_sqrt(), $expected);
+            }
+        };
+    }
+
+    make_integer_sqrt_test!(u64_integer_sqrt, u64, 26, 5);
+    make_integer_sqrt_test!(u32_integer_sqrt, u32, 26, 5);
+    make_integer_sqrt_test!(u16_integer_sqrt, u16, 26, 5);
+    make_integer_sqrt_test!(u8_integer_sqrt, u8, 26, 5);
+    make_integer_sqrt_test!(i64_integer_sqrt, i64, 26, 5);
+    make_integer_sqrt_test!(i32_integer_sqrt, i32, 26, 5);
+    make_integer_sqrt_test!(i16_integer_sqrt, i16, 26, 5);
+    make_integer_sqrt_test!(i8_integer_sqrt, i8, 26, 5);
+    make_integer_sqrt_test!(usize_integer_sqrt, usize, 26, 5);
+    make_integer_sqrt_test!(isize_integer_sqrt, isize, 26, 5);
+    make_integer_sqrt_test!(perfect_square, u32, 81, 9);
+    make_integer_sqrt_test!(zero, u32, 0, 0);
+
+    #[test]
+    fn negative_input_is_none() {
+        let x: i32 = -4;
+        assert_eq!(x.integer_sqrt_checked(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn integer_sqrt_panics_on_negative_input() {
+        let x: i32 = -4;
+        x.integer_sqrt();
+    }
 }
 
 
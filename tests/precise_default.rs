@@ -0,0 +1,52 @@
+//! Exercises the `precise-default` feature, which swaps the crate's single Newton
+//! iteration default for two. Lives as a separate integration test target (rather
+//! than inside `src/lib.rs`'s `tests` module) because it needs the crate compiled
+//! with `--features precise-default` to observe the tighter default, while the rest
+//! of the suite runs against the normal one-iteration default.
+//!
+//! Run with `cargo test --features precise-default --test precise_default`.
+
+#![cfg(feature = "precise-default")]
+
+use quake_inverse_sqrt::{fast_inverse_sqrt_f32, fast_inverse_sqrt_slice, QSqrt};
+
+#[test]
+fn precise_default_is_tighter_than_the_documented_bound() {
+    for x in [0.001f32, 0.25, 1.0, 4.0, 100.0, 1.0e6, 1.0e12] {
+        let approx = fast_inverse_sqrt_f32(x);
+        let exact = 1.0 / x.sqrt();
+        let relative_error = ((approx - exact) / exact).abs();
+        assert!(
+            relative_error < <f32 as QSqrt>::MAX_RELATIVE_ERROR / 10.0,
+            "x={x}: relative error {relative_error} was not tighter than the \
+             single-iteration bound"
+        );
+    }
+}
+
+#[test]
+fn precise_default_applies_to_the_qsqrt_trait_method_too() {
+    // `QSqrt::fast_inverse_sqrt` is the primary, most-used entry point -- every
+    // built-in `QSqrt` impl funnels through it. It must pick up `precise-default`
+    // exactly like the `fast_inverse_sqrt_f32` free function does, not just match
+    // it by coincidence on some inputs.
+    for x in [0.25f32, 1.0, 4.0, 100.0] {
+        assert_eq!(x.fast_inverse_sqrt().unwrap(), fast_inverse_sqrt_f32(x));
+    }
+}
+
+#[test]
+fn precise_default_applies_uniformly_across_a_slice() {
+    // A length that isn't a multiple of 4, so on `sse`/`neon` builds this would
+    // otherwise split between the (one-iteration) SIMD lanes and the
+    // (`precise-default`-aware) scalar remainder -- see `fast_inverse_sqrt_slice`'s
+    // doc comment for why it sits out SIMD dispatch under this feature instead.
+    let input: Vec<f32> = (1..=37).map(|i| i as f32).collect();
+    let mut out = vec![0.0f32; input.len()];
+
+    fast_inverse_sqrt_slice(&input, &mut out);
+
+    for (x, y) in input.iter().zip(out.iter()) {
+        assert_eq!(*y, fast_inverse_sqrt_f32(*x));
+    }
+}